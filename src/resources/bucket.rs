@@ -1,16 +1,205 @@
+use std::sync::Arc;
+
+use async_stream::try_stream;
+use futures_core::stream::Stream;
+
 use super::object::Object;
+use super::s3::S3Error;
 
 pub struct Bucket {
     pub name: String,
     client: aws_sdk_s3::Client,
+    runtime: Arc<tokio::runtime::Runtime>,
 }
 
 impl Bucket {
-    pub(crate) fn new(name: String, client: aws_sdk_s3::Client) -> Self {
-        Self { name, client }
+    pub(crate) fn new(name: String, client: aws_sdk_s3::Client, runtime: Arc<tokio::runtime::Runtime>) -> Self {
+        Self {
+            name,
+            client,
+            runtime,
+        }
     }
 
     pub fn object(&self, key: String) -> Object {
-        Object::new(self.name.clone(), key, self.client.clone())
+        Object::new(self.name.clone(), key, self.client.clone(), self.runtime.clone())
+    }
+
+    /// List every object under `prefix` (or the whole bucket, if `None`),
+    /// paginating through `list_objects_v2` lazily as the stream is
+    /// consumed.
+    pub fn list(&self, prefix: Option<String>) -> impl Stream<Item = Result<ObjectMeta, S3Error>> {
+        let client = self.client.clone();
+        let bucket_name = self.name.clone();
+        try_stream! {
+            let mut continuation_token = None;
+            loop {
+                let mut request = client.list_objects_v2().bucket(&bucket_name);
+                if let Some(prefix) = &prefix {
+                    request = request.prefix(prefix);
+                }
+                if let Some(token) = &continuation_token {
+                    request = request.continuation_token(token);
+                }
+                let response = request
+                    .send()
+                    .await
+                    .map_err(|e| S3Error::AWSS3Error(aws_sdk_s3::Error::from(e)))?;
+                for object in response.contents().unwrap_or_default() {
+                    yield ObjectMeta {
+                        key: object.key().unwrap_or_default().to_string(),
+                        size: object.size(),
+                        last_modified: object.last_modified().cloned(),
+                        etag: object.e_tag().map(String::from),
+                    };
+                }
+                match next_pagination_step(
+                    response.is_truncated(),
+                    response.next_continuation_token().map(String::from),
+                ) {
+                    PaginationStep::Continue(token) => continuation_token = token,
+                    PaginationStep::Done => break,
+                }
+            }
+        }
+    }
+
+    /// Like [`Bucket::list`], but groups keys under `delimiter` (typically
+    /// `/`) into [`ListingEntry::CommonPrefix`] entries instead of
+    /// descending into them, so callers can walk the bucket one directory
+    /// level at a time.
+    pub fn list_with_delimiter(
+        &self,
+        prefix: Option<String>,
+        delimiter: String,
+    ) -> impl Stream<Item = Result<ListingEntry, S3Error>> {
+        let client = self.client.clone();
+        let bucket_name = self.name.clone();
+        try_stream! {
+            let mut continuation_token = None;
+            loop {
+                let mut request = client
+                    .list_objects_v2()
+                    .bucket(&bucket_name)
+                    .delimiter(&delimiter);
+                if let Some(prefix) = &prefix {
+                    request = request.prefix(prefix);
+                }
+                if let Some(token) = &continuation_token {
+                    request = request.continuation_token(token);
+                }
+                let response = request
+                    .send()
+                    .await
+                    .map_err(|e| S3Error::AWSS3Error(aws_sdk_s3::Error::from(e)))?;
+                for object in response.contents().unwrap_or_default() {
+                    yield ListingEntry::Object(ObjectMeta {
+                        key: object.key().unwrap_or_default().to_string(),
+                        size: object.size(),
+                        last_modified: object.last_modified().cloned(),
+                        etag: object.e_tag().map(String::from),
+                    });
+                }
+                for common_prefix in response.common_prefixes().unwrap_or_default() {
+                    if let Some(prefix) = common_prefix.prefix() {
+                        yield ListingEntry::CommonPrefix(prefix.to_string());
+                    }
+                }
+                match next_pagination_step(
+                    response.is_truncated(),
+                    response.next_continuation_token().map(String::from),
+                ) {
+                    PaginationStep::Continue(token) => continuation_token = token,
+                    PaginationStep::Done => break,
+                }
+            }
+        }
+    }
+}
+
+/// What a `list_objects_v2` pagination loop should do next, given the
+/// response's `is_truncated` flag and continuation token. `is_truncated`
+/// alone decides whether to continue — not the mere presence of a token —
+/// so a response that's truncated but (unexpectedly) omits a token still
+/// continues the loop with `None`, rather than stopping early.
+#[derive(Debug, Clone, PartialEq, Eq)]
+enum PaginationStep {
+    Continue(Option<String>),
+    Done,
+}
+
+fn next_pagination_step(is_truncated: bool, next_continuation_token: Option<String>) -> PaginationStep {
+    if is_truncated {
+        PaginationStep::Continue(next_continuation_token)
+    } else {
+        PaginationStep::Done
+    }
+}
+
+/// The metadata `list_objects_v2` returns for a single key, without having
+/// to `head_object` it.
+#[derive(Debug, Clone)]
+pub struct ObjectMeta {
+    pub key: String,
+    pub size: i64,
+    pub last_modified: Option<aws_sdk_s3::types::DateTime>,
+    pub etag: Option<String>,
+}
+
+/// One entry of a [`Bucket::list_with_delimiter`] listing.
+#[derive(Debug, Clone)]
+pub enum ListingEntry {
+    Object(ObjectMeta),
+    CommonPrefix(String),
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn continues_while_truncated_and_carries_the_token_forward() {
+        assert_eq!(
+            next_pagination_step(true, Some("token-1".to_string())),
+            PaginationStep::Continue(Some("token-1".to_string()))
+        );
+    }
+
+    #[test]
+    fn stops_once_the_response_is_not_truncated_even_with_a_stale_token() {
+        assert_eq!(
+            next_pagination_step(false, Some("token-1".to_string())),
+            PaginationStep::Done
+        );
+    }
+
+    #[test]
+    fn continues_on_a_truncated_response_missing_a_token() {
+        assert_eq!(next_pagination_step(true, None), PaginationStep::Continue(None));
+    }
+
+    #[test]
+    fn threads_the_continuation_token_across_a_stubbed_page_sequence() {
+        // Mirrors `list`'s loop driving three pages: the first two
+        // truncated with a token, the last one done.
+        let pages = [
+            (true, Some("token-1".to_string())),
+            (true, Some("token-2".to_string())),
+            (false, None),
+        ];
+        let mut continuation_token = None;
+        let mut requested_tokens = Vec::new();
+        for (is_truncated, next_token) in pages {
+            requested_tokens.push(continuation_token.clone());
+            match next_pagination_step(is_truncated, next_token) {
+                PaginationStep::Continue(token) => continuation_token = token,
+                PaginationStep::Done => break,
+            }
+        }
+        assert_eq!(
+            requested_tokens,
+            vec![None, Some("token-1".to_string()), Some("token-2".to_string())]
+        );
+        assert_eq!(continuation_token, Some("token-2".to_string()));
     }
 }
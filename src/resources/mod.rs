@@ -0,0 +1,9 @@
+mod bucket;
+mod object;
+mod s3;
+mod zstd_seekable;
+
+pub use bucket::{Bucket, ListingEntry, ObjectMeta};
+pub use object::{Object, ObjectOperationError, ObjectWriter};
+pub use s3::{S3Error, S3Uri, S3UriParseError, S3};
+pub use zstd_seekable::{ZstdSeekableObject, ZstdSeekableObjectError};
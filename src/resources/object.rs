@@ -1,10 +1,30 @@
 use std::{
     collections::HashMap,
-    io::{self, Read, Seek},
+    future::Future,
+    io::{self, Read, Seek, Write},
     ops::RangeInclusive,
+    pin::Pin,
+    sync::Arc,
+    task::{Context, Poll},
 };
 
-use tokio::io::AsyncRead;
+use aws_sdk_s3::model::{CompletedMultipartUpload, CompletedPart};
+use aws_sdk_s3::types::ByteStream;
+use percent_encoding::{utf8_percent_encode, AsciiSet, NON_ALPHANUMERIC};
+use tokio::io::{AsyncRead, AsyncWrite};
+
+/// Objects smaller than this are uploaded with a single `put_object` instead
+/// of a multipart upload.
+const DEFAULT_PART_SIZE: usize = 8 * 1024 * 1024;
+
+/// Size of the range GET issued on a read-ahead buffer miss.
+const DEFAULT_BLOCK_SIZE: usize = 8 * 1024 * 1024;
+
+/// S3's limit on the size of a source object for a single `copy_object`
+/// call; larger sources require a multipart copy.
+const MAX_SINGLE_COPY_SIZE: usize = 5 * 1024 * 1024 * 1024;
+/// S3's limit on the size of a single `upload_part_copy` byte range.
+const MAX_COPY_PART_SIZE: usize = 5 * 1024 * 1024 * 1024;
 
 pub struct Object {
     pub bucket_name: String,
@@ -13,10 +33,29 @@ pub struct Object {
     length: Option<usize>,
     last_modified: Option<aws_sdk_s3::types::DateTime>,
     client: aws_sdk_s3::Client,
+    runtime: Arc<tokio::runtime::Runtime>,
+    part_size: usize,
+    write_buffer: Vec<u8>,
+    multipart: Option<MultipartUploadState>,
+    write_finalized: bool,
+    block_size: usize,
+    read_buffer: Vec<u8>,
+    read_buffer_start: usize,
+}
+
+struct MultipartUploadState {
+    upload_id: String,
+    next_part_number: i32,
+    completed_parts: Vec<CompletedPart>,
 }
 
 impl Object {
-    pub(crate) fn new(bucket_name: String, key: String, client: aws_sdk_s3::Client) -> Self {
+    pub(crate) fn new(
+        bucket_name: String,
+        key: String,
+        client: aws_sdk_s3::Client,
+        runtime: Arc<tokio::runtime::Runtime>,
+    ) -> Self {
         Self {
             bucket_name,
             key,
@@ -24,9 +63,33 @@ impl Object {
             length: None,
             last_modified: None,
             client,
+            runtime,
+            part_size: DEFAULT_PART_SIZE,
+            write_buffer: Vec::new(),
+            multipart: None,
+            write_finalized: false,
+            block_size: DEFAULT_BLOCK_SIZE,
+            read_buffer: Vec::new(),
+            read_buffer_start: 0,
         }
     }
 
+    /// Override the multipart upload part-size threshold (default 8 MiB).
+    /// Writes are buffered until they reach this size before a part is
+    /// uploaded.
+    pub fn with_part_size(mut self, part_size: usize) -> Self {
+        self.part_size = part_size;
+        self
+    }
+
+    /// Override the read-ahead block size (default 8 MiB). On a buffer
+    /// miss, `read` issues one range GET of this size starting at the
+    /// current position and satisfies subsequent reads from it.
+    pub fn with_block_size(mut self, block_size: usize) -> Self {
+        self.block_size = block_size;
+        self
+    }
+
     pub async fn get(&self) -> Result<aws_sdk_s3::types::ByteStream, ObjectOperationError> {
         let get_object_request = self
             .client
@@ -41,6 +104,29 @@ impl Object {
         Ok(self.get().await?.into_async_read())
     }
 
+    /// Return an `AsyncWrite` that buffers writes and transparently switches
+    /// to a multipart upload once the buffered data exceeds `part_size`,
+    /// completing (or aborting) the upload when the writer is shut down (or
+    /// dropped before being shut down). The abort on drop is only issued
+    /// when the writer is dropped inside a Tokio runtime.
+    pub fn writer(&self) -> ObjectWriter {
+        ObjectWriter::new(
+            self.bucket_name.clone(),
+            self.key.clone(),
+            self.client.clone(),
+            self.part_size,
+        )
+    }
+
+    /// Upload `body` in a single request. Prefer [`Object::writer`] for
+    /// bodies that do not comfortably fit in memory.
+    pub async fn put(
+        &self,
+        body: impl Into<aws_sdk_s3::types::ByteStream>,
+    ) -> Result<(), ObjectOperationError> {
+        put_object(&self.client, &self.bucket_name, &self.key, body.into()).await
+    }
+
     async fn get_range(
         &self,
         range: RangeInclusive<usize>,
@@ -99,6 +185,439 @@ impl Object {
             }
         }
     }
+
+    /// Upload one full part from `write_buffer`, starting a multipart
+    /// upload first if one is not already in progress.
+    async fn upload_next_part(&mut self) -> Result<(), ObjectOperationError> {
+        if self.multipart.is_none() {
+            let upload_id =
+                create_multipart_upload(&self.client, &self.bucket_name, &self.key).await?;
+            self.multipart = Some(MultipartUploadState {
+                upload_id,
+                next_part_number: 1,
+                completed_parts: Vec::new(),
+            });
+        }
+        // Copy the next part out rather than removing it from
+        // `write_buffer` up front, so that a failed `upload_part` leaves
+        // the data in place instead of silently dropping it.
+        let part_len = next_part_len(self.write_buffer.len(), self.part_size);
+        let part_bytes = self.write_buffer[..part_len].to_vec();
+        let multipart = self
+            .multipart
+            .as_ref()
+            .expect("multipart upload was just started above");
+        let part_number = multipart.next_part_number;
+        let part = upload_part(
+            &self.client,
+            &self.bucket_name,
+            &self.key,
+            &multipart.upload_id,
+            part_number,
+            part_bytes,
+        )
+        .await?;
+        self.write_buffer.drain(..part_len);
+        let multipart = self
+            .multipart
+            .as_mut()
+            .expect("multipart upload was just started above");
+        multipart.completed_parts.push(part);
+        multipart.next_part_number += 1;
+        Ok(())
+    }
+
+    /// Complete (or, for bodies smaller than one part, perform) the upload
+    /// with whatever remains buffered.
+    async fn finish_write(&mut self) -> Result<(), ObjectOperationError> {
+        match self.multipart.take() {
+            Some(mut multipart) => {
+                if let FinishPlan::UploadFinalPartThenComplete =
+                    finish_plan(self.write_buffer.is_empty())
+                {
+                    let part_bytes = std::mem::take(&mut self.write_buffer);
+                    let part_number = multipart.next_part_number;
+                    let part = upload_part(
+                        &self.client,
+                        &self.bucket_name,
+                        &self.key,
+                        &multipart.upload_id,
+                        part_number,
+                        part_bytes,
+                    )
+                    .await?;
+                    multipart.completed_parts.push(part);
+                }
+                complete_multipart_upload(
+                    &self.client,
+                    &self.bucket_name,
+                    &self.key,
+                    &multipart.upload_id,
+                    multipart.completed_parts,
+                )
+                .await
+            }
+            None => {
+                let body = std::mem::take(&mut self.write_buffer);
+                put_object(
+                    &self.client,
+                    &self.bucket_name,
+                    &self.key,
+                    ByteStream::from(body),
+                )
+                .await
+            }
+        }
+    }
+
+    /// Copy this object to `dest_bucket`/`dest_key` entirely server-side,
+    /// without streaming any bytes through the client. Falls back to a
+    /// multipart copy for sources over the 5 GiB single-copy limit.
+    pub async fn copy_to(
+        &self,
+        dest_bucket: &str,
+        dest_key: &str,
+    ) -> Result<(), ObjectOperationError> {
+        let size = self.size().await?;
+        if size <= MAX_SINGLE_COPY_SIZE {
+            copy_object(&self.client, &self.bucket_name, &self.key, dest_bucket, dest_key).await
+        } else {
+            multipart_copy(
+                &self.client,
+                &self.bucket_name,
+                &self.key,
+                dest_bucket,
+                dest_key,
+                size,
+            )
+            .await
+        }
+    }
+
+    /// Copy this object to `dest_bucket`/`dest_key` server-side, then
+    /// delete the source.
+    pub async fn rename_to(
+        &self,
+        dest_bucket: &str,
+        dest_key: &str,
+    ) -> Result<(), ObjectOperationError> {
+        self.copy_to(dest_bucket, dest_key).await?;
+        delete_object(&self.client, &self.bucket_name, &self.key).await
+    }
+
+    async fn size(&self) -> Result<usize, ObjectOperationError> {
+        let response = self
+            .client
+            .head_object()
+            .bucket(&self.bucket_name)
+            .key(&self.key)
+            .send()
+            .await?;
+        usize::try_from(response.content_length()).map_err(|_| ObjectOperationError::Other {
+            msg: "object content length does not fit into into a usize".into(),
+            data: HashMap::from([
+                ("bucket_name".into(), self.bucket_name.clone()),
+                ("key".into(), self.key.clone()),
+            ]),
+        })
+    }
+}
+
+/// Characters `x-amz-copy-source` requires left un-encoded: the unreserved
+/// set (RFC 3986) plus `/`, which separates the bucket from the key and
+/// must stay a literal path separator.
+const COPY_SOURCE_ENCODE_SET: &AsciiSet = &NON_ALPHANUMERIC
+    .remove(b'-')
+    .remove(b'_')
+    .remove(b'.')
+    .remove(b'~')
+    .remove(b'/');
+
+/// Build the `x-amz-copy-source` value `copy_object`/`upload_part_copy`
+/// expect, percent-encoding the key so that spaces, `#`, `?`, `+`, and
+/// non-ASCII bytes don't get misinterpreted as part of the header or a
+/// query string. Bucket names are already DNS-safe and need no encoding.
+fn copy_source(bucket_name: &str, key: &str) -> String {
+    format!(
+        "{bucket_name}/{}",
+        utf8_percent_encode(key, COPY_SOURCE_ENCODE_SET)
+    )
+}
+
+async fn copy_object(
+    client: &aws_sdk_s3::Client,
+    src_bucket: &str,
+    src_key: &str,
+    dest_bucket: &str,
+    dest_key: &str,
+) -> Result<(), ObjectOperationError> {
+    client
+        .copy_object()
+        .copy_source(copy_source(src_bucket, src_key))
+        .bucket(dest_bucket)
+        .key(dest_key)
+        .send()
+        .await?;
+    Ok(())
+}
+
+async fn delete_object(
+    client: &aws_sdk_s3::Client,
+    bucket_name: &str,
+    key: &str,
+) -> Result<(), ObjectOperationError> {
+    client
+        .delete_object()
+        .bucket(bucket_name)
+        .key(key)
+        .send()
+        .await?;
+    Ok(())
+}
+
+async fn upload_part_copy(
+    client: &aws_sdk_s3::Client,
+    src_bucket: &str,
+    src_key: &str,
+    dest_bucket: &str,
+    dest_key: &str,
+    upload_id: &str,
+    part_number: i32,
+    range: RangeInclusive<usize>,
+) -> Result<CompletedPart, ObjectOperationError> {
+    let response = client
+        .upload_part_copy()
+        .copy_source(copy_source(src_bucket, src_key))
+        .copy_source_range(format!(
+            "bytes={start}-{end}",
+            start = range.start(),
+            end = range.end()
+        ))
+        .bucket(dest_bucket)
+        .key(dest_key)
+        .upload_id(upload_id)
+        .part_number(part_number)
+        .send()
+        .await?;
+    let e_tag = response
+        .copy_part_result()
+        .and_then(|result| result.e_tag())
+        .map(String::from)
+        .ok_or_else(|| ObjectOperationError::Other {
+            msg: "upload_part_copy response did not include an etag".into(),
+            data: HashMap::from([
+                ("bucket_name".into(), dest_bucket.into()),
+                ("key".into(), dest_key.into()),
+                ("upload_id".into(), upload_id.into()),
+                ("part_number".into(), part_number.to_string()),
+            ]),
+        })?;
+    Ok(CompletedPart::builder()
+        .e_tag(e_tag)
+        .part_number(part_number)
+        .build())
+}
+
+/// Copy a source object larger than [`MAX_SINGLE_COPY_SIZE`] via a
+/// multipart upload on the destination, issuing one `upload_part_copy` per
+/// `MAX_COPY_PART_SIZE`-sized slice of the source.
+async fn multipart_copy(
+    client: &aws_sdk_s3::Client,
+    src_bucket: &str,
+    src_key: &str,
+    dest_bucket: &str,
+    dest_key: &str,
+    size: usize,
+) -> Result<(), ObjectOperationError> {
+    let upload_id = create_multipart_upload(client, dest_bucket, dest_key).await?;
+    let mut completed_parts = Vec::new();
+    let mut start = 0usize;
+    let mut part_number = 1i32;
+    while start < size {
+        let end = (start + MAX_COPY_PART_SIZE).min(size) - 1;
+        match upload_part_copy(
+            client,
+            src_bucket,
+            src_key,
+            dest_bucket,
+            dest_key,
+            &upload_id,
+            part_number,
+            start..=end,
+        )
+        .await
+        {
+            Ok(part) => completed_parts.push(part),
+            Err(e) => {
+                let _ =
+                    abort_multipart_upload(client, dest_bucket, dest_key, &upload_id).await;
+                return Err(e);
+            }
+        }
+        start = end + 1;
+        part_number += 1;
+    }
+    complete_multipart_upload(client, dest_bucket, dest_key, &upload_id, completed_parts).await
+}
+
+impl Drop for Object {
+    fn drop(&mut self) {
+        if let Some(multipart) = self.multipart.take() {
+            let _ = self.runtime.block_on(abort_multipart_upload(
+                &self.client,
+                &self.bucket_name,
+                &self.key,
+                &multipart.upload_id,
+            ));
+        }
+    }
+}
+
+/// How many bytes `upload_next_part` should take from the front of a
+/// `write_buffer` of length `buffer_len` for the next part.
+fn next_part_len(buffer_len: usize, part_size: usize) -> usize {
+    part_size.min(buffer_len)
+}
+
+/// The length a failed `write()` should truncate `write_buffer` to, so as
+/// to strip the last `buf_len` bytes of its tail back out: since `upload_next_part` only removes bytes once
+/// their part has uploaded successfully, anything still in the tail at
+/// that point is the unconsumed suffix of the `buf` just appended.
+fn rollback_len(buffer_len: usize, buf_len: usize) -> usize {
+    buffer_len - buffer_len.min(buf_len)
+}
+
+/// What `finish_write` needs to do with a trailing, possibly-empty
+/// `write_buffer` once writing has stopped.
+#[derive(Debug, PartialEq, Eq)]
+enum FinishPlan {
+    /// Upload the buffered remainder as a final part, then complete.
+    UploadFinalPartThenComplete,
+    /// Nothing left to upload; just complete with the parts already sent.
+    CompleteOnly,
+}
+
+/// Decide between [`FinishPlan`] variants for a multipart upload in
+/// progress (`finish_write`'s `None` branch, a plain `put_object`, isn't
+/// covered here since it doesn't depend on this decision).
+fn finish_plan(write_buffer_is_empty: bool) -> FinishPlan {
+    if write_buffer_is_empty {
+        FinishPlan::CompleteOnly
+    } else {
+        FinishPlan::UploadFinalPartThenComplete
+    }
+}
+
+async fn create_multipart_upload(
+    client: &aws_sdk_s3::Client,
+    bucket_name: &str,
+    key: &str,
+) -> Result<String, ObjectOperationError> {
+    let response = client
+        .create_multipart_upload()
+        .bucket(bucket_name)
+        .key(key)
+        .send()
+        .await?;
+    response
+        .upload_id()
+        .map(String::from)
+        .ok_or_else(|| ObjectOperationError::Other {
+            msg: "create_multipart_upload response did not include an upload id".into(),
+            data: HashMap::from([
+                ("bucket_name".into(), bucket_name.into()),
+                ("key".into(), key.into()),
+            ]),
+        })
+}
+
+async fn upload_part(
+    client: &aws_sdk_s3::Client,
+    bucket_name: &str,
+    key: &str,
+    upload_id: &str,
+    part_number: i32,
+    body: Vec<u8>,
+) -> Result<CompletedPart, ObjectOperationError> {
+    let response = client
+        .upload_part()
+        .bucket(bucket_name)
+        .key(key)
+        .upload_id(upload_id)
+        .part_number(part_number)
+        .body(ByteStream::from(body))
+        .send()
+        .await?;
+    let e_tag = response
+        .e_tag()
+        .map(String::from)
+        .ok_or_else(|| ObjectOperationError::Other {
+            msg: "upload_part response did not include an etag".into(),
+            data: HashMap::from([
+                ("bucket_name".into(), bucket_name.into()),
+                ("key".into(), key.into()),
+                ("upload_id".into(), upload_id.into()),
+                ("part_number".into(), part_number.to_string()),
+            ]),
+        })?;
+    Ok(CompletedPart::builder()
+        .e_tag(e_tag)
+        .part_number(part_number)
+        .build())
+}
+
+async fn complete_multipart_upload(
+    client: &aws_sdk_s3::Client,
+    bucket_name: &str,
+    key: &str,
+    upload_id: &str,
+    parts: Vec<CompletedPart>,
+) -> Result<(), ObjectOperationError> {
+    client
+        .complete_multipart_upload()
+        .bucket(bucket_name)
+        .key(key)
+        .upload_id(upload_id)
+        .multipart_upload(
+            CompletedMultipartUpload::builder()
+                .set_parts(Some(parts))
+                .build(),
+        )
+        .send()
+        .await?;
+    Ok(())
+}
+
+async fn abort_multipart_upload(
+    client: &aws_sdk_s3::Client,
+    bucket_name: &str,
+    key: &str,
+    upload_id: &str,
+) -> Result<(), ObjectOperationError> {
+    client
+        .abort_multipart_upload()
+        .bucket(bucket_name)
+        .key(key)
+        .upload_id(upload_id)
+        .send()
+        .await?;
+    Ok(())
+}
+
+async fn put_object(
+    client: &aws_sdk_s3::Client,
+    bucket_name: &str,
+    key: &str,
+    body: aws_sdk_s3::types::ByteStream,
+) -> Result<(), ObjectOperationError> {
+    client
+        .put_object()
+        .bucket(bucket_name)
+        .key(key)
+        .body(body)
+        .send()
+        .await?;
+    Ok(())
 }
 
 #[derive(Debug, thiserror::Error)]
@@ -108,6 +627,28 @@ pub enum ObjectOperationError {
     GetObject(#[from] aws_sdk_s3::types::SdkError<aws_sdk_s3::error::GetObjectError>),
     #[error(transparent)]
     HeadObject(#[from] aws_sdk_s3::types::SdkError<aws_sdk_s3::error::HeadObjectError>),
+    #[error(transparent)]
+    PutObject(#[from] aws_sdk_s3::types::SdkError<aws_sdk_s3::error::PutObjectError>),
+    #[error(transparent)]
+    CreateMultipartUpload(
+        #[from] aws_sdk_s3::types::SdkError<aws_sdk_s3::error::CreateMultipartUploadError>,
+    ),
+    #[error(transparent)]
+    UploadPart(#[from] aws_sdk_s3::types::SdkError<aws_sdk_s3::error::UploadPartError>),
+    #[error(transparent)]
+    CompleteMultipartUpload(
+        #[from] aws_sdk_s3::types::SdkError<aws_sdk_s3::error::CompleteMultipartUploadError>,
+    ),
+    #[error(transparent)]
+    AbortMultipartUpload(
+        #[from] aws_sdk_s3::types::SdkError<aws_sdk_s3::error::AbortMultipartUploadError>,
+    ),
+    #[error(transparent)]
+    CopyObject(#[from] aws_sdk_s3::types::SdkError<aws_sdk_s3::error::CopyObjectError>),
+    #[error(transparent)]
+    UploadPartCopy(#[from] aws_sdk_s3::types::SdkError<aws_sdk_s3::error::UploadPartCopyError>),
+    #[error(transparent)]
+    DeleteObject(#[from] aws_sdk_s3::types::SdkError<aws_sdk_s3::error::DeleteObjectError>),
     #[error("{msg}")]
     Other {
         msg: String,
@@ -115,29 +656,15 @@ pub enum ObjectOperationError {
     },
 }
 
-impl Read for Object {
-    fn read(&mut self, buf: &mut [u8]) -> io::Result<usize> {
-        if self.length.is_none() {
-            let rt = tokio::runtime::Builder::new_current_thread()
-            .enable_all()
-            .build()
-            .expect(
-                "expect to be able to build a tokio runtime, without which the rest of the code cannot be executed",
-            );
-            rt.block_on(self.refresh_metadata())
-                .map_err(|e| io::Error::new(io::ErrorKind::Other, e))?;
-        }
-        if self.position >= self.length.unwrap() {
-            return Ok(0);
-        }
-        let num_bytes_to_read = buf.len();
-        let rt = tokio::runtime::Builder::new_current_thread()
-            .enable_all()
-            .build()
-            .expect(
-                "expect to be able to build a tokio runtime, without which the rest of the code cannot be executed",
-            );
-        match rt.block_on(self.get_range(self.position..=(self.position + num_bytes_to_read - 1))) {
+impl Object {
+    /// Issue one range GET of `block_size` bytes starting at `position`
+    /// and stash the result in `read_buffer`, so that `read` can be
+    /// satisfied from memory until the buffer is exhausted.
+    fn fill_read_buffer(&mut self) -> io::Result<()> {
+        let length = self.length.unwrap();
+        let start = self.position;
+        let end = (start + self.block_size).min(length) - 1;
+        match self.runtime.block_on(self.get_range(start..=end)) {
             Err(ooe) => match ooe {
                 ObjectOperationError::GetObject(sdk_err) => match sdk_err {
                     aws_sdk_s3::types::SdkError::TimeoutError(_) => {
@@ -167,32 +694,106 @@ impl Read for Object {
                 },
                 _ => unreachable!("received a type of ObjectOperationError from get_range that should not be possible!!!")
             },
-            Ok(byte_stream) => match rt.block_on(byte_stream.collect()) {
+            Ok(byte_stream) => match self.runtime.block_on(byte_stream.collect()) {
                 Err(err) => Err(io::Error::new(io::ErrorKind::Other, err)),
                 Ok(agg_bytes) => {
-                    let bytes = agg_bytes.into_bytes();
-                    let received_num_bytes = bytes.len();
-                    buf[..received_num_bytes].copy_from_slice(&bytes);
-                    self.position += received_num_bytes;
-                    Ok(received_num_bytes)
+                    self.read_buffer = agg_bytes.into_bytes().to_vec();
+                    self.read_buffer_start = start;
+                    Ok(())
                 }
             },
         }
     }
 }
 
+impl Read for Object {
+    fn read(&mut self, buf: &mut [u8]) -> io::Result<usize> {
+        if self.length.is_none() {
+            let runtime = self.runtime.clone();
+            runtime
+                .block_on(self.refresh_metadata())
+                .map_err(|e| io::Error::new(io::ErrorKind::Other, e))?;
+        }
+        if self.position >= self.length.unwrap() {
+            return Ok(0);
+        }
+        let buffer_covers_position = self.position >= self.read_buffer_start
+            && self.position < self.read_buffer_start + self.read_buffer.len();
+        if !buffer_covers_position {
+            self.fill_read_buffer()?;
+        }
+        let offset_in_buffer = self.position - self.read_buffer_start;
+        let available = &self.read_buffer[offset_in_buffer..];
+        let num_bytes_to_copy = buf.len().min(available.len());
+        buf[..num_bytes_to_copy].copy_from_slice(&available[..num_bytes_to_copy]);
+        self.position += num_bytes_to_copy;
+        Ok(num_bytes_to_copy)
+    }
+}
+
+/// Mirrors the blocking design of the `Read`/`Seek` impls: writes are
+/// buffered and, once a full part accumulates, uploaded via a multipart
+/// upload started lazily on the first part. Call `flush` after the last
+/// `write` to finalize the upload (or, for bodies under one part, perform
+/// it as a single `put_object`). Once finalized, `flush` is a no-op and
+/// further `write`s fail, so calling `flush` more than once (e.g. from
+/// defensive or checkpointing callers) cannot start a second, independent
+/// upload that silently overwrites the first.
+impl Write for Object {
+    fn write(&mut self, buf: &[u8]) -> io::Result<usize> {
+        if self.write_finalized {
+            return Err(io::Error::new(
+                io::ErrorKind::Other,
+                "write called after the upload was already finalized by flush",
+            ));
+        }
+        self.write_buffer.extend_from_slice(buf);
+        while self.write_buffer.len() >= self.part_size {
+            let runtime = self.runtime.clone();
+            if let Err(e) = runtime.block_on(self.upload_next_part()) {
+                // `upload_next_part` only removes bytes from `write_buffer`
+                // once their part has uploaded successfully, so everything
+                // still sitting in the buffer's tail at this point is the
+                // unconsumed suffix of `buf` appended above. Strip it back
+                // out so a caller that retries the same `buf` after this
+                // error doesn't duplicate it. If a prefix of `buf` already
+                // went out in an uploaded part, report just that prefix as
+                // written, so the caller resumes after it instead of
+                // sending it again.
+                let remaining = self.write_buffer.len();
+                self.write_buffer.truncate(rollback_len(remaining, buf.len()));
+                let uploaded_from_buf = buf.len().saturating_sub(remaining);
+                if uploaded_from_buf > 0 {
+                    return Ok(uploaded_from_buf);
+                }
+                return Err(io::Error::new(io::ErrorKind::Other, e));
+            }
+        }
+        Ok(buf.len())
+    }
+
+    fn flush(&mut self) -> io::Result<()> {
+        if self.write_finalized {
+            return Ok(());
+        }
+        let runtime = self.runtime.clone();
+        runtime
+            .block_on(self.finish_write())
+            .map_err(|e| io::Error::new(io::ErrorKind::Other, e))?;
+        self.write_finalized = true;
+        Ok(())
+    }
+}
+
 impl Seek for Object {
     fn seek(&mut self, pos: io::SeekFrom) -> io::Result<u64> {
         if self.length.is_none() {
-            let rt = tokio::runtime::Builder::new_current_thread()
-            .enable_all()
-            .build()
-            .expect(
-                "expect to be able to build a tokio runtime, without which the rest of the code cannot be executed",
-            );
-            rt.block_on(self.refresh_metadata())
+            let runtime = self.runtime.clone();
+            runtime
+                .block_on(self.refresh_metadata())
                 .map_err(|e| io::Error::new(io::ErrorKind::Other, e))?;
         }
+        self.read_buffer.clear();
         match pos {
             io::SeekFrom::Start(s) => {
                 if s > u64::try_from(self.length.unwrap()).map_err(|_| {
@@ -263,3 +864,406 @@ impl Seek for Object {
         })
     }
 }
+
+type BoxedResultFuture<T> = Pin<Box<dyn Future<Output = Result<T, ObjectOperationError>> + Send>>;
+
+enum WriteOp {
+    Idle,
+    CreatingMultipart {
+        future: BoxedResultFuture<String>,
+        pending_part: Vec<u8>,
+    },
+    UploadingPart(BoxedResultFuture<CompletedPart>),
+    Completing(BoxedResultFuture<()>),
+}
+
+/// The `AsyncWrite` counterpart to [`Object::writer`]. See that method for
+/// the buffering and multipart-upload behaviour; `poll_shutdown` is what
+/// finalizes (or aborts, if dropped beforehand) the upload. Once finalized,
+/// `poll_shutdown` is a no-op, so calling `shutdown` more than once on the
+/// same writer can't re-run (or re-poll an already-completed) finalization.
+pub struct ObjectWriter {
+    bucket_name: String,
+    key: String,
+    client: aws_sdk_s3::Client,
+    part_size: usize,
+    buffer: Vec<u8>,
+    multipart: Option<MultipartUploadState>,
+    op: WriteOp,
+    finalized: bool,
+}
+
+impl ObjectWriter {
+    fn new(bucket_name: String, key: String, client: aws_sdk_s3::Client, part_size: usize) -> Self {
+        Self {
+            bucket_name,
+            key,
+            client,
+            part_size,
+            buffer: Vec::new(),
+            multipart: None,
+            op: WriteOp::Idle,
+            finalized: false,
+        }
+    }
+
+    fn upload_part_future(&self, upload_id: String, part_number: i32, body: Vec<u8>) -> BoxedResultFuture<CompletedPart> {
+        let client = self.client.clone();
+        let bucket_name = self.bucket_name.clone();
+        let key = self.key.clone();
+        Box::pin(async move { upload_part(&client, &bucket_name, &key, &upload_id, part_number, body).await })
+    }
+
+    fn complete_future(&self, upload_id: String, parts: Vec<CompletedPart>) -> BoxedResultFuture<()> {
+        let client = self.client.clone();
+        let bucket_name = self.bucket_name.clone();
+        let key = self.key.clone();
+        Box::pin(async move { complete_multipart_upload(&client, &bucket_name, &key, &upload_id, parts).await })
+    }
+
+    fn put_future(&self, body: Vec<u8>) -> BoxedResultFuture<()> {
+        let client = self.client.clone();
+        let bucket_name = self.bucket_name.clone();
+        let key = self.key.clone();
+        Box::pin(async move { put_object(&client, &bucket_name, &key, ByteStream::from(body)).await })
+    }
+
+    fn create_multipart_future(&self) -> BoxedResultFuture<String> {
+        let client = self.client.clone();
+        let bucket_name = self.bucket_name.clone();
+        let key = self.key.clone();
+        Box::pin(async move { create_multipart_upload(&client, &bucket_name, &key).await })
+    }
+
+    /// Take one full part from `buffer`, starting a multipart upload first
+    /// if one isn't already in progress.
+    fn start_part_upload(&mut self) {
+        let part_bytes = if self.buffer.len() > self.part_size {
+            let remainder = self.buffer.split_off(self.part_size);
+            std::mem::replace(&mut self.buffer, remainder)
+        } else {
+            std::mem::take(&mut self.buffer)
+        };
+        match &self.multipart {
+            Some(multipart) => {
+                let future =
+                    self.upload_part_future(multipart.upload_id.clone(), multipart.next_part_number, part_bytes);
+                self.op = WriteOp::UploadingPart(future);
+            }
+            None => {
+                let future = self.create_multipart_future();
+                self.op = WriteOp::CreatingMultipart {
+                    future,
+                    pending_part: part_bytes,
+                };
+            }
+        }
+    }
+}
+
+impl AsyncWrite for ObjectWriter {
+    fn poll_write(mut self: Pin<&mut Self>, cx: &mut Context<'_>, buf: &[u8]) -> Poll<io::Result<usize>> {
+        loop {
+            match &mut self.op {
+                WriteOp::Idle => {
+                    if self.buffer.len() < self.part_size {
+                        self.buffer.extend_from_slice(buf);
+                        return Poll::Ready(Ok(buf.len()));
+                    }
+                    self.start_part_upload();
+                }
+                WriteOp::CreatingMultipart { future, .. } => match future.as_mut().poll(cx) {
+                    Poll::Pending => return Poll::Pending,
+                    Poll::Ready(Err(e)) => {
+                        let WriteOp::CreatingMultipart { pending_part, .. } =
+                            std::mem::replace(&mut self.op, WriteOp::Idle)
+                        else {
+                            unreachable!()
+                        };
+                        self.buffer.splice(0..0, pending_part);
+                        return Poll::Ready(Err(io::Error::new(io::ErrorKind::Other, e)));
+                    }
+                    Poll::Ready(Ok(upload_id)) => {
+                        let WriteOp::CreatingMultipart { pending_part, .. } =
+                            std::mem::replace(&mut self.op, WriteOp::Idle)
+                        else {
+                            unreachable!()
+                        };
+                        self.multipart = Some(MultipartUploadState {
+                            upload_id: upload_id.clone(),
+                            next_part_number: 1,
+                            completed_parts: Vec::new(),
+                        });
+                        self.op = WriteOp::UploadingPart(self.upload_part_future(upload_id, 1, pending_part));
+                    }
+                },
+                WriteOp::UploadingPart(future) => match future.as_mut().poll(cx) {
+                    Poll::Pending => return Poll::Pending,
+                    Poll::Ready(Err(e)) => {
+                        self.op = WriteOp::Idle;
+                        return Poll::Ready(Err(io::Error::new(io::ErrorKind::Other, e)));
+                    }
+                    Poll::Ready(Ok(part)) => {
+                        let multipart = self
+                            .multipart
+                            .as_mut()
+                            .expect("multipart state set before the first part upload starts");
+                        multipart.completed_parts.push(part);
+                        multipart.next_part_number += 1;
+                        self.op = WriteOp::Idle;
+                    }
+                },
+                WriteOp::Completing(_) => {
+                    unreachable!("poll_write called while the upload is being finalized")
+                }
+            }
+        }
+    }
+
+    fn poll_flush(mut self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<io::Result<()>> {
+        loop {
+            match &mut self.op {
+                WriteOp::Idle => return Poll::Ready(Ok(())),
+                WriteOp::CreatingMultipart { future, .. } => match future.as_mut().poll(cx) {
+                    Poll::Pending => return Poll::Pending,
+                    Poll::Ready(Err(e)) => {
+                        self.op = WriteOp::Idle;
+                        return Poll::Ready(Err(io::Error::new(io::ErrorKind::Other, e)));
+                    }
+                    Poll::Ready(Ok(upload_id)) => {
+                        let WriteOp::CreatingMultipart { pending_part, .. } =
+                            std::mem::replace(&mut self.op, WriteOp::Idle)
+                        else {
+                            unreachable!()
+                        };
+                        self.multipart = Some(MultipartUploadState {
+                            upload_id: upload_id.clone(),
+                            next_part_number: 1,
+                            completed_parts: Vec::new(),
+                        });
+                        self.op = WriteOp::UploadingPart(self.upload_part_future(upload_id, 1, pending_part));
+                    }
+                },
+                WriteOp::UploadingPart(future) => match future.as_mut().poll(cx) {
+                    Poll::Pending => return Poll::Pending,
+                    Poll::Ready(Err(e)) => {
+                        self.op = WriteOp::Idle;
+                        return Poll::Ready(Err(io::Error::new(io::ErrorKind::Other, e)));
+                    }
+                    Poll::Ready(Ok(part)) => {
+                        let multipart = self
+                            .multipart
+                            .as_mut()
+                            .expect("multipart state set before the first part upload starts");
+                        multipart.completed_parts.push(part);
+                        multipart.next_part_number += 1;
+                        self.op = WriteOp::Idle;
+                    }
+                },
+                WriteOp::Completing(_) => {
+                    unreachable!("poll_flush called while the upload is being finalized")
+                }
+            }
+        }
+    }
+
+    fn poll_shutdown(mut self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<io::Result<()>> {
+        if self.finalized {
+            return Poll::Ready(Ok(()));
+        }
+        loop {
+            match &mut self.op {
+                WriteOp::Idle => match self.multipart.take() {
+                    Some(multipart) if !self.buffer.is_empty() => {
+                        let body = std::mem::take(&mut self.buffer);
+                        let future = self.upload_part_future(multipart.upload_id.clone(), multipart.next_part_number, body);
+                        self.multipart = Some(multipart);
+                        self.op = WriteOp::UploadingPart(future);
+                    }
+                    Some(multipart) => {
+                        let future = self.complete_future(multipart.upload_id.clone(), multipart.completed_parts.clone());
+                        self.op = WriteOp::Completing(future);
+                    }
+                    None if self.buffer.len() >= self.part_size => self.start_part_upload(),
+                    None => {
+                        let body = std::mem::take(&mut self.buffer);
+                        self.op = WriteOp::Completing(self.put_future(body));
+                    }
+                },
+                WriteOp::CreatingMultipart { future, .. } => match future.as_mut().poll(cx) {
+                    Poll::Pending => return Poll::Pending,
+                    Poll::Ready(Err(e)) => {
+                        self.op = WriteOp::Idle;
+                        return Poll::Ready(Err(io::Error::new(io::ErrorKind::Other, e)));
+                    }
+                    Poll::Ready(Ok(upload_id)) => {
+                        let WriteOp::CreatingMultipart { pending_part, .. } =
+                            std::mem::replace(&mut self.op, WriteOp::Idle)
+                        else {
+                            unreachable!()
+                        };
+                        self.multipart = Some(MultipartUploadState {
+                            upload_id: upload_id.clone(),
+                            next_part_number: 1,
+                            completed_parts: Vec::new(),
+                        });
+                        self.op = WriteOp::UploadingPart(self.upload_part_future(upload_id, 1, pending_part));
+                    }
+                },
+                WriteOp::UploadingPart(future) => match future.as_mut().poll(cx) {
+                    Poll::Pending => return Poll::Pending,
+                    Poll::Ready(Err(e)) => {
+                        self.op = WriteOp::Idle;
+                        return Poll::Ready(Err(io::Error::new(io::ErrorKind::Other, e)));
+                    }
+                    Poll::Ready(Ok(part)) => {
+                        let multipart = self
+                            .multipart
+                            .as_mut()
+                            .expect("multipart state set before the first part upload starts");
+                        multipart.completed_parts.push(part);
+                        multipart.next_part_number += 1;
+                        self.op = WriteOp::Idle;
+                    }
+                },
+                WriteOp::Completing(future) => match future.as_mut().poll(cx) {
+                    Poll::Pending => return Poll::Pending,
+                    Poll::Ready(Err(e)) => {
+                        self.op = WriteOp::Idle;
+                        return Poll::Ready(Err(io::Error::new(io::ErrorKind::Other, e)));
+                    }
+                    Poll::Ready(Ok(())) => {
+                        self.multipart = None;
+                        self.op = WriteOp::Idle;
+                        self.finalized = true;
+                        return Poll::Ready(Ok(()));
+                    }
+                },
+            }
+        }
+    }
+}
+
+impl Drop for ObjectWriter {
+    fn drop(&mut self) {
+        if let Some(multipart) = self.multipart.take() {
+            let client = self.client.clone();
+            let bucket_name = self.bucket_name.clone();
+            let key = self.key.clone();
+            if let Ok(handle) = tokio::runtime::Handle::try_current() {
+                handle.spawn(async move {
+                    let _ = abort_multipart_upload(&client, &bucket_name, &key, &multipart.upload_id).await;
+                });
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn dummy_client() -> aws_sdk_s3::Client {
+        let config = aws_sdk_s3::Config::builder()
+            .region(aws_sdk_s3::Region::new("us-east-1"))
+            .credentials_provider(aws_sdk_s3::Credentials::new("test", "test", None, None, "test"))
+            .build();
+        aws_sdk_s3::Client::from_conf(config)
+    }
+
+    fn completed_part(part_number: i32) -> CompletedPart {
+        CompletedPart::builder()
+            .e_tag(format!("etag-{part_number}"))
+            .part_number(part_number)
+            .build()
+    }
+
+    fn noop_waker() -> std::task::Waker {
+        fn no_op(_: *const ()) {}
+        fn clone(_: *const ()) -> std::task::RawWaker {
+            raw_waker()
+        }
+        fn raw_waker() -> std::task::RawWaker {
+            static VTABLE: std::task::RawWakerVTable =
+                std::task::RawWakerVTable::new(clone, no_op, no_op, no_op);
+            std::task::RawWaker::new(std::ptr::null(), &VTABLE)
+        }
+        unsafe { std::task::Waker::from_raw(raw_waker()) }
+    }
+
+    #[test]
+    fn next_part_len_caps_at_part_size() {
+        assert_eq!(next_part_len(20, 8), 8);
+        assert_eq!(next_part_len(8, 8), 8);
+        assert_eq!(next_part_len(5, 8), 5);
+        assert_eq!(next_part_len(0, 8), 0);
+    }
+
+    #[test]
+    fn rollback_len_strips_only_the_unconsumed_suffix_of_the_last_write() {
+        // none of `buf` uploaded yet, nothing buffered before it either
+        assert_eq!(rollback_len(12, 12), 0);
+        // the first 7 bytes of `buf` went out in a successful part upload;
+        // the 5 left in the buffer are its unconsumed suffix
+        assert_eq!(rollback_len(5, 12), 0);
+        // none of `buf` was consumed, plus 4 bytes left over from before
+        assert_eq!(rollback_len(16, 12), 4);
+    }
+
+    #[test]
+    fn finish_plan_uploads_a_final_part_only_when_something_is_buffered() {
+        assert_eq!(finish_plan(true), FinishPlan::CompleteOnly);
+        assert_eq!(finish_plan(false), FinishPlan::UploadFinalPartThenComplete);
+    }
+
+    #[test]
+    fn poll_shutdown_is_idempotent_after_completion() {
+        let mut writer = ObjectWriter::new("bucket".into(), "key".into(), dummy_client(), DEFAULT_PART_SIZE);
+        writer.multipart = Some(MultipartUploadState {
+            upload_id: "upload-id".into(),
+            next_part_number: 2,
+            completed_parts: vec![completed_part(1)],
+        });
+        writer.op = WriteOp::Completing(Box::pin(async { Ok(()) }));
+
+        let waker = noop_waker();
+        let mut cx = Context::from_waker(&waker);
+
+        let first = Pin::new(&mut writer).poll_shutdown(&mut cx);
+        assert!(matches!(first, Poll::Ready(Ok(()))));
+        assert!(writer.multipart.is_none());
+
+        // Before the fix this re-polled the same already-finished boxed
+        // future and panicked with "async fn resumed after completion".
+        let second = Pin::new(&mut writer).poll_shutdown(&mut cx);
+        assert!(matches!(second, Poll::Ready(Ok(()))));
+    }
+
+    #[test]
+    fn completed_parts_stay_in_order_across_successive_part_uploads() {
+        let mut writer = ObjectWriter::new("bucket".into(), "key".into(), dummy_client(), DEFAULT_PART_SIZE);
+        writer.multipart = Some(MultipartUploadState {
+            upload_id: "upload-id".into(),
+            next_part_number: 1,
+            completed_parts: Vec::new(),
+        });
+
+        let waker = noop_waker();
+        let mut cx = Context::from_waker(&waker);
+
+        for part_number in 1..=3 {
+            writer.op = WriteOp::UploadingPart(Box::pin(async move { Ok(completed_part(part_number)) }));
+            let result = Pin::new(&mut writer).poll_write(&mut cx, b"");
+            assert!(matches!(result, Poll::Ready(Ok(0))));
+        }
+
+        let multipart = writer.multipart.as_ref().unwrap();
+        let part_numbers: Vec<i32> = multipart
+            .completed_parts
+            .iter()
+            .map(|p| p.part_number().expect("part number was set by completed_part"))
+            .collect();
+        assert_eq!(part_numbers, vec![1, 2, 3]);
+        assert_eq!(multipart.next_part_number, 4);
+    }
+}
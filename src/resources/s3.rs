@@ -1,7 +1,16 @@
+use std::sync::Arc;
+use std::time::Duration;
+
 use super::bucket::Bucket;
+use super::object::Object;
+
+const S3_SCHEME: &str = "s3://";
+const DEFAULT_MAX_ATTEMPTS: u32 = 3;
+const DEFAULT_RETRY_INITIAL_BACKOFF: Duration = Duration::from_millis(100);
 
 pub struct S3 {
     client: aws_sdk_s3::Client,
+    runtime: Arc<tokio::runtime::Runtime>,
 }
 
 impl S3 {
@@ -16,19 +25,230 @@ impl S3 {
     pub fn with_aws_sdk_config(config: &aws_config::SdkConfig) -> Self {
         Self {
             client: aws_sdk_s3::Client::new(config),
+            runtime: new_runtime(),
         }
     }
 
+    /// Build an `S3` resource with control over retry, timeout, and
+    /// connection-pool behaviour, in addition to the environment-provided
+    /// AWS configuration.
+    pub fn builder() -> S3Builder {
+        S3Builder::default()
+    }
+
     pub fn bucket(&self, name: String) -> Bucket {
-        Bucket::new(name, self.client.clone())
+        Bucket::new(name, self.client.clone(), self.runtime.clone())
+    }
+
+    /// Construct an `Object` handle directly from a `s3://bucket/key` URI.
+    pub fn object_from_uri(&self, uri: &str) -> Result<Object, S3Error> {
+        let uri: S3Uri = uri.parse()?;
+        Ok(self.bucket(uri.bucket).object(uri.key))
+    }
+}
+
+fn new_runtime() -> Arc<tokio::runtime::Runtime> {
+    Arc::new(
+        tokio::runtime::Builder::new_current_thread()
+            .enable_all()
+            .build()
+            .expect(
+                "expect to be able to build a tokio runtime, without which the rest of the code cannot be executed",
+            ),
+    )
+}
+
+/// Builder for [`S3`] exposing the resilience and concurrency knobs that
+/// `S3::default`/`S3::with_aws_sdk_config` don't: retry behaviour, dispatch
+/// timeouts, and the HTTP connector's connection pool size.
+pub struct S3Builder {
+    max_connections: Option<usize>,
+    retry_initial_backoff: Duration,
+    max_attempts: u32,
+    connect_timeout: Option<Duration>,
+    read_timeout: Option<Duration>,
+}
+
+impl Default for S3Builder {
+    fn default() -> Self {
+        Self {
+            max_connections: None,
+            retry_initial_backoff: DEFAULT_RETRY_INITIAL_BACKOFF,
+            max_attempts: DEFAULT_MAX_ATTEMPTS,
+            connect_timeout: None,
+            read_timeout: None,
+        }
+    }
+}
+
+impl S3Builder {
+    /// Cap the number of concurrent connections the HTTP connector keeps
+    /// open to S3. Useful for high-fanout workloads driving many `Object`
+    /// range GETs in parallel.
+    pub fn max_connections(mut self, max_connections: usize) -> Self {
+        self.max_connections = Some(max_connections);
+        self
+    }
+
+    /// Retry transient failures (`TimeoutError`/`DispatchFailure`) with
+    /// exponential backoff, starting at `initial_backoff` and giving up
+    /// after `max_attempts` total attempts.
+    pub fn retry_initial_backoff(mut self, initial_backoff: Duration, max_attempts: u32) -> Self {
+        self.retry_initial_backoff = initial_backoff;
+        self.max_attempts = max_attempts;
+        self
+    }
+
+    /// Timeout for establishing the TCP connection to S3.
+    pub fn connect_timeout(mut self, connect_timeout: Duration) -> Self {
+        self.connect_timeout = Some(connect_timeout);
+        self
+    }
+
+    /// Timeout for reading a response once the request has been sent.
+    pub fn read_timeout(mut self, read_timeout: Duration) -> Self {
+        self.read_timeout = Some(read_timeout);
+        self
+    }
+
+    /// Load the AWS configuration from the environment with the configured
+    /// resilience settings applied, and build the `S3` resource.
+    pub async fn build(self) -> S3 {
+        let retry_config = aws_config::retry::RetryConfig::standard()
+            .with_max_attempts(self.max_attempts)
+            .with_initial_backoff(self.retry_initial_backoff);
+
+        let mut timeout_config_builder = aws_config::timeout::TimeoutConfig::builder();
+        if let Some(connect_timeout) = self.connect_timeout {
+            timeout_config_builder = timeout_config_builder.connect_timeout(connect_timeout);
+        }
+        if let Some(read_timeout) = self.read_timeout {
+            timeout_config_builder = timeout_config_builder.read_timeout(read_timeout);
+        }
+
+        let aws_sdk_config = aws_config::from_env()
+            .retry_config(retry_config)
+            .timeout_config(timeout_config_builder.build())
+            .load()
+            .await;
+
+        let mut client_config_builder = aws_sdk_s3::config::Builder::from(&aws_sdk_config);
+        if let Some(max_connections) = self.max_connections {
+            let https_connector = hyper_rustls::HttpsConnectorBuilder::new()
+                .with_webpki_roots()
+                .https_or_http()
+                .enable_http1()
+                .enable_http2()
+                .build();
+            let connector_settings = aws_smithy_client::http_connector::ConnectorSettings::builder()
+                .connect_timeout_optional(self.connect_timeout)
+                .read_timeout_optional(self.read_timeout)
+                .build();
+            let hyper_builder = hyper::client::Builder::default();
+            let connector = aws_smithy_client::hyper_ext::Adapter::builder()
+                .hyper_builder(hyper_builder)
+                .connector_settings(connector_settings)
+                .build(https_connector);
+            // `pool_max_idle_per_host` only bounds kept-alive idle
+            // connections, not in-flight ones, so it can't cap concurrency
+            // on its own: a fan-out of more than `max_connections` requests
+            // would still dispatch that many connections at once. Wrap the
+            // connector in a `tower` concurrency limit instead, which backs
+            // requests up past the cap rather than letting them all fire.
+            let connector = aws_smithy_client::erase::DynConnector::new(
+                tower::limit::ConcurrencyLimit::new(connector, max_connections),
+            );
+            client_config_builder = client_config_builder.http_connector(connector);
+        }
+
+        S3 {
+            client: aws_sdk_s3::Client::from_conf(client_config_builder.build()),
+            runtime: new_runtime(),
+        }
+    }
+}
+
+/// A parsed `s3://bucket/path/to/key` URI.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct S3Uri {
+    pub bucket: String,
+    pub key: String,
+}
+
+impl std::str::FromStr for S3Uri {
+    type Err = S3UriParseError;
+
+    fn from_str(uri: &str) -> Result<Self, Self::Err> {
+        let rest = uri.strip_prefix(S3_SCHEME).ok_or(S3UriParseError::MissingScheme)?;
+        let (bucket, key) = rest.split_once('/').ok_or(S3UriParseError::MissingBucketOrKey)?;
+        if bucket.is_empty() || key.is_empty() {
+            return Err(S3UriParseError::MissingBucketOrKey);
+        }
+        Ok(Self {
+            bucket: bucket.to_string(),
+            key: key.to_string(),
+        })
     }
 }
 
+#[derive(Debug, thiserror::Error)]
+pub enum S3UriParseError {
+    #[error("uri is missing the \"s3://\" scheme")]
+    MissingScheme,
+    #[error("uri is missing a bucket name or a key")]
+    MissingBucketOrKey,
+}
+
 #[derive(Debug, thiserror::Error)]
 pub enum S3Error {
     /// An error that happened during an AWS S3 api operation.
     #[error(transparent)]
     AWSS3Error(aws_sdk_s3::Error),
+    #[error(transparent)]
+    S3UriParseError(#[from] S3UriParseError),
     #[error("{0}")]
     Other(String),
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn parses_bucket_and_key() {
+        let uri: S3Uri = "s3://my-bucket/path/to/key".parse().unwrap();
+        assert_eq!(uri.bucket, "my-bucket");
+        assert_eq!(uri.key, "path/to/key");
+    }
+
+    #[test]
+    fn preserves_embedded_slashes_in_the_key() {
+        let uri: S3Uri = "s3://my-bucket/a/b/c.txt".parse().unwrap();
+        assert_eq!(uri.bucket, "my-bucket");
+        assert_eq!(uri.key, "a/b/c.txt");
+    }
+
+    #[test]
+    fn rejects_a_missing_scheme() {
+        let err = "my-bucket/key".parse::<S3Uri>().unwrap_err();
+        assert!(matches!(err, S3UriParseError::MissingScheme));
+    }
+
+    #[test]
+    fn rejects_a_missing_key() {
+        let err = "s3://my-bucket".parse::<S3Uri>().unwrap_err();
+        assert!(matches!(err, S3UriParseError::MissingBucketOrKey));
+    }
+
+    #[test]
+    fn rejects_an_empty_bucket() {
+        let err = "s3:///key".parse::<S3Uri>().unwrap_err();
+        assert!(matches!(err, S3UriParseError::MissingBucketOrKey));
+    }
+
+    #[test]
+    fn rejects_an_empty_key() {
+        let err = "s3://my-bucket/".parse::<S3Uri>().unwrap_err();
+        assert!(matches!(err, S3UriParseError::MissingBucketOrKey));
+    }
+}
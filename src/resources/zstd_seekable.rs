@@ -0,0 +1,367 @@
+use std::io::{self, Read, Seek};
+
+use super::object::Object;
+
+/// Magic number of the skippable frame the zstd seekable format appends to
+/// the end of the file to hold the seek table.
+const SEEKABLE_FRAME_MAGIC_NUMBER: u32 = 0x184D_2A5E;
+/// Magic number at the very end of the file, after the seek table entries.
+const SEEKABLE_MAGIC_NUMBER: u32 = 0x8F92_EAB1;
+/// `Number_of_Frames(4) + Seek_Table_Descriptor(1) + Seekable_Magic_Number(4)`.
+const SEEK_TABLE_FOOTER_SIZE: usize = 9;
+/// `Skippable_Magic_Number(4) + Frame_Size(4)`.
+const SKIPPABLE_FRAME_HEADER_SIZE: usize = 8;
+/// Set in the seek table descriptor byte when each entry carries a trailing
+/// xxhash32 checksum of the decompressed frame.
+const CHECKSUM_FLAG: u8 = 0x80;
+
+struct FrameIndexEntry {
+    compressed_offset: usize,
+    compressed_size: usize,
+    decompressed_offset: usize,
+    decompressed_size: usize,
+}
+
+/// A `Read + Seek` wrapper around an [`Object`] written in the [zstd
+/// seekable format][spec], giving random access over the decompressed
+/// contents without downloading the whole object.
+///
+/// [spec]: https://github.com/facebook/zstd/blob/dev/contrib/seekable_format/zstd_seekable_compression_format.md
+pub struct ZstdSeekableObject {
+    object: Object,
+    frames: Vec<FrameIndexEntry>,
+    decompressed_length: usize,
+    position: usize,
+    cached_frame: Option<(usize, Vec<u8>)>,
+}
+
+impl ZstdSeekableObject {
+    /// Range-GET the trailing seek table of `object` and build a cumulative
+    /// decompressed-offset index over its frames.
+    pub fn open(mut object: Object) -> Result<Self, ZstdSeekableObjectError> {
+        let total_len = object.seek(io::SeekFrom::End(0))?;
+        let total_len = usize::try_from(total_len)
+            .map_err(|_| ZstdSeekableObjectError::InvalidSeekTable("object is too large"))?;
+        if total_len < SEEK_TABLE_FOOTER_SIZE {
+            return Err(ZstdSeekableObjectError::InvalidSeekTable(
+                "object is too small to contain a seek table footer",
+            ));
+        }
+
+        let mut footer = [0u8; SEEK_TABLE_FOOTER_SIZE];
+        object.seek(io::SeekFrom::Start(
+            (total_len - SEEK_TABLE_FOOTER_SIZE) as u64,
+        ))?;
+        object.read_exact(&mut footer)?;
+        let (number_of_frames, descriptor) = parse_seek_table_footer(&footer)?;
+
+        let entry_size = 8 + if descriptor & CHECKSUM_FLAG != 0 { 4 } else { 0 };
+        let entries_size = number_of_frames * entry_size;
+        let skippable_frame_content_size = entries_size + SEEK_TABLE_FOOTER_SIZE;
+        let skippable_header_offset = total_len
+            .checked_sub(SKIPPABLE_FRAME_HEADER_SIZE + skippable_frame_content_size)
+            .ok_or(ZstdSeekableObjectError::InvalidSeekTable(
+                "seek table is larger than the object itself",
+            ))?;
+
+        let mut skippable_header = [0u8; SKIPPABLE_FRAME_HEADER_SIZE];
+        object.seek(io::SeekFrom::Start(skippable_header_offset as u64))?;
+        object.read_exact(&mut skippable_header)?;
+        parse_skippable_frame_header(&skippable_header, skippable_frame_content_size)?;
+
+        let mut entries = vec![0u8; entries_size];
+        object.read_exact(&mut entries)?;
+        let frames = parse_frame_entries(&entries, entry_size);
+        let decompressed_length = frames
+            .last()
+            .map(|frame| frame.decompressed_offset + frame.decompressed_size)
+            .unwrap_or(0);
+
+        Ok(Self {
+            object,
+            frames,
+            decompressed_length,
+            position: 0,
+            cached_frame: None,
+        })
+    }
+
+    /// Binary search the cumulative index for the frame containing
+    /// decompressed position `p`.
+    fn frame_containing(&self, p: usize) -> Option<usize> {
+        frame_containing_in(&self.frames, p)
+    }
+
+    /// Range-GET and fully decompress the frame at `index`, caching it as
+    /// the most recently used frame for cheap sequential reads.
+    fn decompressed_frame(&mut self, index: usize) -> io::Result<&[u8]> {
+        if !matches!(&self.cached_frame, Some((cached_index, _)) if *cached_index == index) {
+            let frame = &self.frames[index];
+            self.object
+                .seek(io::SeekFrom::Start(frame.compressed_offset as u64))?;
+            let mut compressed = vec![0u8; frame.compressed_size];
+            self.object.read_exact(&mut compressed)?;
+            let decompressed = zstd::stream::decode_all(&compressed[..])?;
+            self.cached_frame = Some((index, decompressed));
+        }
+        Ok(&self.cached_frame.as_ref().unwrap().1)
+    }
+}
+
+/// Parse the trailing `Number_of_Frames(4) + Seek_Table_Descriptor(1) +
+/// Seekable_Magic_Number(4)` footer, returning the frame count and
+/// descriptor byte.
+fn parse_seek_table_footer(
+    footer: &[u8; SEEK_TABLE_FOOTER_SIZE],
+) -> Result<(usize, u8), ZstdSeekableObjectError> {
+    let number_of_frames = u32::from_le_bytes(footer[0..4].try_into().unwrap()) as usize;
+    let descriptor = footer[4];
+    let magic_number = u32::from_le_bytes(footer[5..9].try_into().unwrap());
+    if magic_number != SEEKABLE_MAGIC_NUMBER {
+        return Err(ZstdSeekableObjectError::InvalidSeekTable(
+            "seekable magic number mismatch",
+        ));
+    }
+    Ok((number_of_frames, descriptor))
+}
+
+/// Validate the skippable frame header that wraps the seek table: its
+/// magic number and its declared size against the seek table's actual
+/// size.
+fn parse_skippable_frame_header(
+    header: &[u8; SKIPPABLE_FRAME_HEADER_SIZE],
+    expected_content_size: usize,
+) -> Result<(), ZstdSeekableObjectError> {
+    let skippable_magic = u32::from_le_bytes(header[0..4].try_into().unwrap());
+    if skippable_magic != SEEKABLE_FRAME_MAGIC_NUMBER {
+        return Err(ZstdSeekableObjectError::InvalidSeekTable(
+            "skippable frame magic number mismatch",
+        ));
+    }
+    let frame_size = u32::from_le_bytes(header[4..8].try_into().unwrap()) as usize;
+    if frame_size != expected_content_size {
+        return Err(ZstdSeekableObjectError::InvalidSeekTable(
+            "skippable frame size does not match the seek table it contains",
+        ));
+    }
+    Ok(())
+}
+
+/// Build the cumulative compressed/decompressed offset index from the raw
+/// seek table entries (each `Compressed_Size(4) + Decompressed_Size(4)`,
+/// plus an optional trailing checksum that's part of `entry_size` but
+/// otherwise ignored).
+fn parse_frame_entries(entries: &[u8], entry_size: usize) -> Vec<FrameIndexEntry> {
+    let mut frames = Vec::with_capacity(entries.len() / entry_size.max(1));
+    let mut compressed_offset = 0usize;
+    let mut decompressed_offset = 0usize;
+    for entry in entries.chunks_exact(entry_size) {
+        let compressed_size = u32::from_le_bytes(entry[0..4].try_into().unwrap()) as usize;
+        let decompressed_size = u32::from_le_bytes(entry[4..8].try_into().unwrap()) as usize;
+        frames.push(FrameIndexEntry {
+            compressed_offset,
+            compressed_size,
+            decompressed_offset,
+            decompressed_size,
+        });
+        compressed_offset += compressed_size;
+        decompressed_offset += decompressed_size;
+    }
+    frames
+}
+
+/// Binary search `frames`' cumulative decompressed-offset index for the
+/// frame containing decompressed position `p`.
+fn frame_containing_in(frames: &[FrameIndexEntry], p: usize) -> Option<usize> {
+    frames
+        .binary_search_by(|frame| {
+            if p < frame.decompressed_offset {
+                std::cmp::Ordering::Greater
+            } else if p >= frame.decompressed_offset + frame.decompressed_size {
+                std::cmp::Ordering::Less
+            } else {
+                std::cmp::Ordering::Equal
+            }
+        })
+        .ok()
+}
+
+impl Read for ZstdSeekableObject {
+    fn read(&mut self, buf: &mut [u8]) -> io::Result<usize> {
+        if self.position >= self.decompressed_length {
+            return Ok(0);
+        }
+        let frame_index = self.frame_containing(self.position).ok_or_else(|| {
+            io::Error::new(
+                io::ErrorKind::InvalidData,
+                "decompressed position is not covered by any frame in the seek table",
+            )
+        })?;
+        let frame_start = self.frames[frame_index].decompressed_offset;
+        let decompressed = self.decompressed_frame(frame_index)?;
+        let offset_in_frame = self.position - frame_start;
+        let available = &decompressed[offset_in_frame..];
+        let num_bytes_to_copy = buf.len().min(available.len());
+        buf[..num_bytes_to_copy].copy_from_slice(&available[..num_bytes_to_copy]);
+        self.position += num_bytes_to_copy;
+        Ok(num_bytes_to_copy)
+    }
+}
+
+impl Seek for ZstdSeekableObject {
+    fn seek(&mut self, pos: io::SeekFrom) -> io::Result<u64> {
+        let new_position = match pos {
+            io::SeekFrom::Start(s) => s as i64,
+            io::SeekFrom::End(s) => self.decompressed_length as i64 + s,
+            io::SeekFrom::Current(s) => self.position as i64 + s,
+        };
+        if new_position < 0 {
+            return Err(io::Error::new(
+                io::ErrorKind::InvalidInput,
+                "tried to seek to a negative offset",
+            ));
+        }
+        self.position = (new_position as usize).min(self.decompressed_length);
+        Ok(self.position as u64)
+    }
+}
+
+#[derive(Debug, thiserror::Error)]
+#[non_exhaustive]
+pub enum ZstdSeekableObjectError {
+    #[error(transparent)]
+    Io(#[from] io::Error),
+    #[error("invalid zstd seek table: {0}")]
+    InvalidSeekTable(&'static str),
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn footer(number_of_frames: u32, descriptor: u8, magic_number: u32) -> [u8; SEEK_TABLE_FOOTER_SIZE] {
+        let mut footer = [0u8; SEEK_TABLE_FOOTER_SIZE];
+        footer[0..4].copy_from_slice(&number_of_frames.to_le_bytes());
+        footer[4] = descriptor;
+        footer[5..9].copy_from_slice(&magic_number.to_le_bytes());
+        footer
+    }
+
+    #[test]
+    fn parses_a_valid_footer() {
+        let (number_of_frames, descriptor) =
+            parse_seek_table_footer(&footer(3, CHECKSUM_FLAG, SEEKABLE_MAGIC_NUMBER)).unwrap();
+        assert_eq!(number_of_frames, 3);
+        assert_eq!(descriptor, CHECKSUM_FLAG);
+    }
+
+    #[test]
+    fn rejects_a_footer_with_the_wrong_magic_number() {
+        let err = parse_seek_table_footer(&footer(3, 0, 0xDEAD_BEEF)).unwrap_err();
+        assert!(matches!(err, ZstdSeekableObjectError::InvalidSeekTable(_)));
+    }
+
+    fn skippable_header(magic_number: u32, frame_size: u32) -> [u8; SKIPPABLE_FRAME_HEADER_SIZE] {
+        let mut header = [0u8; SKIPPABLE_FRAME_HEADER_SIZE];
+        header[0..4].copy_from_slice(&magic_number.to_le_bytes());
+        header[4..8].copy_from_slice(&frame_size.to_le_bytes());
+        header
+    }
+
+    #[test]
+    fn parses_a_valid_skippable_header() {
+        let header = skippable_header(SEEKABLE_FRAME_MAGIC_NUMBER, 42);
+        assert!(parse_skippable_frame_header(&header, 42).is_ok());
+    }
+
+    #[test]
+    fn rejects_a_skippable_header_with_the_wrong_magic_number() {
+        let header = skippable_header(0xDEAD_BEEF, 42);
+        let err = parse_skippable_frame_header(&header, 42).unwrap_err();
+        assert!(matches!(err, ZstdSeekableObjectError::InvalidSeekTable(_)));
+    }
+
+    #[test]
+    fn rejects_a_skippable_header_whose_size_does_not_match() {
+        let header = skippable_header(SEEKABLE_FRAME_MAGIC_NUMBER, 42);
+        let err = parse_skippable_frame_header(&header, 41).unwrap_err();
+        assert!(matches!(err, ZstdSeekableObjectError::InvalidSeekTable(_)));
+    }
+
+    fn entry(compressed_size: u32, decompressed_size: u32) -> [u8; 8] {
+        let mut entry = [0u8; 8];
+        entry[0..4].copy_from_slice(&compressed_size.to_le_bytes());
+        entry[4..8].copy_from_slice(&decompressed_size.to_le_bytes());
+        entry
+    }
+
+    #[test]
+    fn builds_a_cumulative_frame_index() {
+        let mut entries = Vec::new();
+        entries.extend_from_slice(&entry(10, 100));
+        entries.extend_from_slice(&entry(20, 200));
+        entries.extend_from_slice(&entry(5, 50));
+        let frames = parse_frame_entries(&entries, 8);
+
+        assert_eq!(frames.len(), 3);
+        assert_eq!(frames[0].compressed_offset, 0);
+        assert_eq!(frames[0].decompressed_offset, 0);
+        assert_eq!(frames[1].compressed_offset, 10);
+        assert_eq!(frames[1].decompressed_offset, 100);
+        assert_eq!(frames[2].compressed_offset, 30);
+        assert_eq!(frames[2].decompressed_offset, 300);
+    }
+
+    #[test]
+    fn skips_trailing_checksum_bytes_in_wider_entries() {
+        let mut entries = Vec::new();
+        entries.extend_from_slice(&entry(10, 100));
+        entries.extend_from_slice(&0xFFFF_FFFFu32.to_le_bytes()); // checksum, ignored
+        entries.extend_from_slice(&entry(20, 200));
+        entries.extend_from_slice(&0xFFFF_FFFFu32.to_le_bytes());
+        let frames = parse_frame_entries(&entries, 12);
+
+        assert_eq!(frames.len(), 2);
+        assert_eq!(frames[1].decompressed_offset, 100);
+        assert_eq!(frames[1].decompressed_size, 200);
+    }
+
+    fn index(sizes: &[(usize, usize)]) -> Vec<FrameIndexEntry> {
+        let mut frames = Vec::new();
+        let mut compressed_offset = 0;
+        let mut decompressed_offset = 0;
+        for &(compressed_size, decompressed_size) in sizes {
+            frames.push(FrameIndexEntry {
+                compressed_offset,
+                compressed_size,
+                decompressed_offset,
+                decompressed_size,
+            });
+            compressed_offset += compressed_size;
+            decompressed_offset += decompressed_size;
+        }
+        frames
+    }
+
+    #[test]
+    fn finds_the_frame_containing_a_position() {
+        let frames = index(&[(10, 100), (20, 200), (5, 50)]);
+        assert_eq!(frame_containing_in(&frames, 0), Some(0));
+        assert_eq!(frame_containing_in(&frames, 99), Some(0));
+        assert_eq!(frame_containing_in(&frames, 100), Some(1));
+        assert_eq!(frame_containing_in(&frames, 299), Some(1));
+        assert_eq!(frame_containing_in(&frames, 300), Some(2));
+        assert_eq!(frame_containing_in(&frames, 349), Some(2));
+    }
+
+    #[test]
+    fn rejects_a_position_past_the_end_of_the_index() {
+        let frames = index(&[(10, 100), (20, 200)]);
+        assert_eq!(frame_containing_in(&frames, 300), None);
+    }
+
+    #[test]
+    fn rejects_an_empty_index() {
+        assert_eq!(frame_containing_in(&[], 0), None);
+    }
+}